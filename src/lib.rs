@@ -55,25 +55,20 @@ use bitbuf::{BitBuf, BitBufMut, BitSliceMut, CappedFill, Fill, Insufficient, Una
 use core::ops::Deref;
 
 fn encode_len(n: u64) -> u8 {
-    if n < 2u64.pow(7) {
-        0
-    } else if n < 2u64.pow(14) {
-        1
-    } else if n < 2u64.pow(20) {
-        2
-    } else if n < 2u64.pow(28) {
-        3
-    } else if n < 2u64.pow(35) {
-        4
-    } else if n < 2u64.pow(42) {
-        5
-    } else if n < 2u64.pow(49) {
-        6
-    } else if n < 2u64.pow(56) {
-        7
-    } else {
-        8
-    }
+    // Significant bit width of the value, treating 0 as a single bit.
+    let bits = (64 - n.leading_zeros()).max(1);
+    // The length is the number of payload capacities the value overflows
+    // (7/14/20/28/35/42/49/56, matching `decode_len`). Summing the comparisons
+    // keeps this a branchless arithmetic chain in hot serialization loops; the
+    // 57..=64-bit case saturates the sum at the full 9-byte length of 8.
+    (bits > 7) as u8
+        + (bits > 14) as u8
+        + (bits > 20) as u8
+        + (bits > 28) as u8
+        + (bits > 35) as u8
+        + (bits > 42) as u8
+        + (bits > 49) as u8
+        + (bits > 56) as u8
 }
 
 fn decode_len(n: u8) -> u8 {
@@ -163,7 +158,99 @@ impl AsyncReadVlq {
     }
 }
 
+pub struct ReadVlqIter<'a, B> {
+    buf: &'a mut B,
+    done: bool,
+}
+
+impl<'a, B: BitBuf> Iterator for ReadVlqIter<'a, B> {
+    type Item = Result<u64, Insufficient>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.buf.len() == 0 {
+            return None;
+        }
+        let before = self.buf.len();
+        match Vlq::read(self.buf) {
+            Ok(value) => {
+                drain_to_alignment(self.buf, before - self.buf.len());
+                Some(Ok(value))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+pub struct ReadSignedVlqIter<'a, B> {
+    buf: &'a mut B,
+    done: bool,
+}
+
+impl<'a, B: BitBuf> Iterator for ReadSignedVlqIter<'a, B> {
+    type Item = Result<i64, Insufficient>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.buf.len() == 0 {
+            return None;
+        }
+        let before = self.buf.len();
+        match Vlq::read_signed(self.buf) {
+            Ok(value) => {
+                drain_to_alignment(self.buf, before - self.buf.len());
+                Some(Ok(value))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+// A written vlq is byte-aligned (`decode_len(first) * 8` bits), but the u20
+// category decodes only 23 of its 24 bits. Drain the trailing padding so
+// packed iteration resumes on the next byte boundary.
+fn drain_to_alignment<B: BitBuf>(buf: &mut B, consumed: usize) {
+    for _ in 0..(8 - consumed % 8) % 8 {
+        if buf.read_bool().is_none() {
+            break;
+        }
+    }
+}
+
+pub struct AsyncWriteVlq {
+    data: Vlq,
+    len: usize,
+    cursor: usize,
+}
+
+impl AsyncWriteVlq {
+    pub fn poll_write<B: BitBufMut>(&mut self, buf: &mut B) -> Result<(), Insufficient> {
+        let data: &[u8] = &self.data.0;
+        while self.cursor < self.len {
+            let byte = data[self.cursor / 8];
+            let bit = (byte >> (7 - self.cursor % 8)) & 1 == 1;
+            buf.write_bool(bit)?;
+            self.cursor += 1;
+        }
+        Ok(())
+    }
+}
+
 impl Vlq {
+    pub fn async_write(value: u64) -> AsyncWriteVlq {
+        let data = Vlq::from(value);
+        let len = data.len() * 8;
+        AsyncWriteVlq {
+            data,
+            len,
+            cursor: 0,
+        }
+    }
+
     pub fn async_read() -> AsyncReadVlq {
         AsyncReadVlq {
             len_buf: Fill::new([0u8; 1]),
@@ -172,6 +259,86 @@ impl Vlq {
         }
     }
 
+    pub fn from_signed(input: i64) -> Self {
+        Vlq::from(((input << 1) ^ (input >> 63)) as u64)
+    }
+
+    pub fn read_signed<B: BitBuf>(buf: &mut B) -> Result<i64, Insufficient> {
+        let z = Vlq::read(buf)?;
+        Ok(((z >> 1) as i64) ^ -((z & 1) as i64))
+    }
+
+    #[cfg(feature = "bytes")]
+    pub fn read_from_bytes<B: bytes::Buf>(buf: &mut B) -> Result<u64, Insufficient> {
+        if buf.remaining() < 1 {
+            return Err(Insufficient);
+        }
+        // A complete Vlq is byte-aligned, so the first byte determines the
+        // total length and the rest can be copied in one shot. Peek the length
+        // byte without advancing so nothing is consumed on the `Insufficient`
+        // retry path.
+        let first = buf.chunk()[0];
+        let len = decode_len(first) as usize;
+        if buf.remaining() < len {
+            return Err(Insufficient);
+        }
+        let mut data = [0u8; 9];
+        data[0] = first;
+        buf.advance(1);
+        buf.copy_to_slice(&mut data[1..len]);
+        Vlq::read(&mut bitbuf::BitSlice::new(&data[..len]))
+    }
+
+    #[cfg(feature = "bytes")]
+    pub fn write_to_bytes<B: bytes::BufMut>(&self, buf: &mut B) {
+        buf.put_slice(&self.0[..decode_len(self.0[0]) as usize]);
+    }
+
+    pub fn read_iter<B: BitBuf>(buf: &mut B) -> ReadVlqIter<B> {
+        ReadVlqIter { buf, done: false }
+    }
+
+    pub fn read_signed_iter<B: BitBuf>(buf: &mut B) -> ReadSignedVlqIter<B> {
+        ReadSignedVlqIter { buf, done: false }
+    }
+
+    pub fn encode_leb128(n: u64) -> ([u8; 10], usize) {
+        let mut encoded = [0u8; 10];
+        let mut value = n;
+        let mut len = 0;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                encoded[len] = byte | 0x80;
+                len += 1;
+            } else {
+                encoded[len] = byte;
+                len += 1;
+                break;
+            }
+        }
+        (encoded, len)
+    }
+
+    pub fn read_leb128<B: BitBuf>(buf: &mut B) -> Result<u64, Insufficient> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        for _ in 0..10 {
+            let byte = buf.read_byte().ok_or(Insufficient)?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+        // A well-formed u64 varint is at most 10 bytes; a continuation bit on
+        // the tenth byte means the input is malformed or overflows u64. The
+        // crate's only decode error is `Insufficient`, so surface it as that
+        // rather than aborting on untrusted input.
+        Err(Insufficient)
+    }
+
     pub fn read<B: BitBuf>(buf: &mut B) -> Result<u64, Insufficient> {
         let mut len = 0usize;
         while let Some(item) = buf.read_bool() {
@@ -326,4 +493,171 @@ mod test {
     fn u64_upper_bound() {
         read_write(core::u64::MAX, 9);
     }
+
+    fn read_write_signed(value: i64, bytes: usize) {
+        // Create backing storage
+        let mut data = vec![0u8; bytes];
+
+        // Create a buffer handle for writing
+        let mut buf = BitSliceMut::new(&mut data);
+
+        // Create a vlq from a signed value
+        let vlq = Vlq::from_signed(value);
+
+        // Write vlq to buffer
+        buf.write_aligned_all(&*vlq)
+            .expect("writing signed vlq to buffer failed");
+
+        // Ensure the correct byte length was written
+        assert_eq!(buf.len(), bytes * 8);
+
+        // Read vlq to ensure value is preserved
+        assert_eq!(
+            Vlq::read_signed(&mut BitSlice::new(&data)).expect("reading signed vlq failed"),
+            value
+        );
+    }
+
+    #[test]
+    fn signed_zero() {
+        read_write_signed(0, 1);
+    }
+
+    #[test]
+    fn signed_negative_one() {
+        // ZigZag maps -1 to 1, fitting in a single byte
+        read_write_signed(-1, 1);
+    }
+
+    #[test]
+    fn signed_small_bounds() {
+        read_write_signed(63, 1);
+        read_write_signed(-64, 1);
+    }
+
+    #[test]
+    fn signed_min_max() {
+        read_write_signed(core::i64::MIN, 9);
+        read_write_signed(core::i64::MAX, 9);
+    }
+
+    fn read_write_leb128(value: u64, bytes: usize) {
+        let (encoded, len) = Vlq::encode_leb128(value);
+
+        // Ensure the expected number of continuation-delimited bytes was produced
+        assert_eq!(len, bytes);
+
+        // Round-trip through a byte-aligned buffer
+        let mut data = vec![0u8; len];
+        let mut buf = BitSliceMut::new(&mut data);
+        buf.write_aligned_all(&encoded[..len])
+            .expect("writing leb128 to buffer failed");
+
+        assert_eq!(
+            Vlq::read_leb128(&mut BitSlice::new(&data)).expect("reading leb128 failed"),
+            value
+        );
+    }
+
+    #[test]
+    fn leb128_single_byte() {
+        read_write_leb128(0, 1);
+        read_write_leb128(127, 1);
+    }
+
+    #[test]
+    fn leb128_two_bytes() {
+        read_write_leb128(128, 2);
+        read_write_leb128(16383, 2);
+    }
+
+    #[test]
+    fn leb128_max() {
+        read_write_leb128(core::u64::MAX, 10);
+    }
+
+    #[test]
+    fn iter_packed() {
+        let values = [0u64, 78, 2u64.pow(14), 2u64.pow(15), 2u64.pow(20), core::u64::MAX];
+        let mut data = vec![0u8; 32];
+        let mut buf = BitSliceMut::new(&mut data);
+        let mut total = 0;
+        for &value in &values {
+            let vlq = Vlq::from(value);
+            buf.write_aligned_all(&*vlq)
+                .expect("writing vlq to buffer failed");
+            total += vlq.len();
+        }
+
+        let mut buf = BitSlice::new(&data[..total]);
+        let decoded: Result<Vec<u64>, _> = Vlq::read_iter(&mut buf).collect();
+        assert_eq!(decoded.expect("reading packed vlqs failed"), values);
+    }
+
+    #[test]
+    fn iter_packed_signed() {
+        let values = [0i64, -1, 63, -64, 2i64.pow(14), -(2i64.pow(15)), core::i64::MIN];
+        let mut data = vec![0u8; 32];
+        let mut buf = BitSliceMut::new(&mut data);
+        let mut total = 0;
+        for &value in &values {
+            let vlq = Vlq::from_signed(value);
+            buf.write_aligned_all(&*vlq)
+                .expect("writing signed vlq to buffer failed");
+            total += vlq.len();
+        }
+
+        let mut buf = BitSlice::new(&data[..total]);
+        let decoded: Result<Vec<i64>, _> = Vlq::read_signed_iter(&mut buf).collect();
+        assert_eq!(decoded.expect("reading packed signed vlqs failed"), values);
+    }
+
+    #[test]
+    fn async_write_resumes() {
+        let value = 300u64;
+
+        let mut writer = Vlq::async_write(value);
+
+        // A one-byte destination can't hold the full two-byte vlq.
+        let mut first = [0u8; 1];
+        let mut small = BitSliceMut::new(&mut first);
+        assert!(writer.poll_write(&mut small).is_err());
+
+        // The full first byte was written and the cursor stopped at the first
+        // unwritten bit, ready to resume.
+        assert_eq!(small.len(), 8);
+        assert_eq!(writer.cursor, 8);
+
+        // Resume into a buffer with room for the remainder.
+        let mut second = [0u8; 1];
+        let mut rest = BitSliceMut::new(&mut second);
+        writer.poll_write(&mut rest).expect("resumed write failed");
+
+        // The two halves concatenate into the complete vlq.
+        let data = [first[0], second[0]];
+        assert_eq!(
+            Vlq::read(&mut BitSlice::new(&data)).expect("reading resumed vlq failed"),
+            value
+        );
+    }
+
+    #[cfg(feature = "bytes")]
+    fn read_write_bytes(value: u64) {
+        let mut data = bytes::BytesMut::new();
+        Vlq::from(value).write_to_bytes(&mut data);
+        let mut data = data.freeze();
+        assert_eq!(
+            Vlq::read_from_bytes(&mut data).expect("reading vlq from bytes failed"),
+            value
+        );
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_round_trip() {
+        read_write_bytes(0);
+        read_write_bytes(78);
+        read_write_bytes(2u64.pow(20));
+        read_write_bytes(core::u64::MAX);
+    }
 }